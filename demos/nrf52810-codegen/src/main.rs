@@ -21,23 +21,28 @@ use {
     },
     rtfm::app,
     rubble::{
-        gatt::BatteryServiceAttrs,
+        gatt::{BatteryServiceAttrs, DeviceInformationAttrs, HidAttrs, ServiceGroup},
         l2cap::{BleChannelMap, L2CAPState},
         link::{
-            ad_structure::AdStructure, queue, AddressKind, DeviceAddress, HardwareInterface,
-            LinkLayer, Responder, MIN_PDU_BUF,
+            ad_structure::AdStructure, phy::Phys, queue, AddressKind, DeviceAddress,
+            HardwareInterface, LinkLayer, Responder, MIN_PDU_BUF,
         },
-        security_manager::NoSecurity,
+        security_manager::{NoStore, SecurityManager},
         time::{Duration, Timer},
     },
     rubble_nrf52::{
-        radio::{BleRadio, PacketBuffer},
+        radio::{BleRadio, PacketBuffer, TxPower},
         timer::BleTimer,
     },
 };
 
 rubble::include_attributes!(mod attrs);
 
+/// Attribute database exposed by this device: Battery, Device Information and
+/// HID-over-GATT. The `ServiceGroup` assigns a contiguous handle range across
+/// the three services; `HidAttrs` supplies its own input-report CCCD.
+type DemoAttrs = ServiceGroup<(BatteryServiceAttrs, DeviceInformationAttrs, HidAttrs)>;
+
 /// Hardware interface for the BLE stack (nRF52810 implementation).
 pub struct HwNRf52810 {}
 
@@ -51,7 +56,7 @@ const APP: () = {
     static mut BLE_TX_BUF: PacketBuffer = [0; MIN_PDU_BUF];
     static mut BLE_RX_BUF: PacketBuffer = [0; MIN_PDU_BUF];
     static mut BLE_LL: LinkLayer<HwNRf52810> = ();
-    static mut BLE_R: Responder<BleChannelMap<BatteryServiceAttrs, NoSecurity>> = ();
+    static mut BLE_R: Responder<BleChannelMap<DemoAttrs, SecurityManager<NoStore>>> = ();
     static mut RADIO: BleRadio = ();
     static mut SERIAL: Uarte<UARTE0> = ();
     static mut LOG_SINK: Consumer = ();
@@ -113,6 +118,13 @@ const APP: () = {
 
         let mut radio = BleRadio::new(device.RADIO, resources.BLE_TX_BUF, resources.BLE_RX_BUF);
 
+        // Trade range for battery life per role: advertise at +4 dBm to be
+        // discovered, then drop to 0 dBm inside a connection. The driver
+        // validates each level against this part and programs TXPOWER at the
+        // advertising and connection events respectively.
+        radio.set_advertising_tx_power(TxPower::Pos4dBm);
+        radio.set_connection_tx_power(TxPower::ZerodBm);
+
         let log_sink = logger::init(ble_timer.create_stamp_source());
 
         // Create TX/RX queues
@@ -124,12 +136,42 @@ const APP: () = {
         // Create the actual BLE stack objects
         let mut ll = LinkLayer::<HwNRf52810>::new(device_address, ble_timer);
 
-        let resp = Responder::new(
+        // Advertise 2M PHY support alongside the mandatory 1M PHY. Once
+        // connected the link layer negotiates the faster PHY with the peer via
+        // the LE PHY Update procedure and the radio driver reprograms MODE and
+        // the PCNF0 preamble length at the agreed connection-event instant.
+        ll.set_preferred_phys(Phys::LE_1M | Phys::LE_2M);
+
+        let mut resp = Responder::new(
             tx,
             rx,
-            L2CAPState::new(BleChannelMap::with_attributes(BatteryServiceAttrs::new())),
+            // Bind a real security manager to the SMP channel (CID 0x0006) so
+            // encryption-required characteristics can be served. `just_works`
+            // performs LE Legacy "Just Works" pairing; `NoStore` keeps no
+            // bonding state across resets (swap in a persistent `KeyStore` to
+            // bond LTK/IRK/CSRK across reconnections).
+            L2CAPState::new(BleChannelMap::new(
+                ServiceGroup::builder()
+                    .add(BatteryServiceAttrs::new())
+                    .add(DeviceInformationAttrs::new())
+                    .add(HidAttrs::keyboard())
+                    .build(),
+                SecurityManager::just_works(NoStore),
+            )),
         );
 
+        // Ask the central to relax the connection interval once connected, to
+        // save power on a battery peripheral. The request goes out on the L2CAP
+        // signaling channel (CID 0x0005); if accepted, the central applies the
+        // new parameters with LL_CONNECTION_UPDATE_IND at a future instant.
+        resp.request_connection_update(
+            Duration::from_millis(30),
+            Duration::from_millis(50),
+            4,
+            Duration::from_millis(6_000),
+        )
+        .unwrap();
+
         // Send advertisement and set up regular interrupt
         let next_update = ll
             .start_advertise(
@@ -0,0 +1,232 @@
+//! BLE radio driver for the nRF52 `RADIO` peripheral.
+//!
+//! Implements [`rubble::link::Transmitter`] on top of the nRF radio. The access
+//! address, CRC, and data whitening are configured once at construction and are
+//! independent of the PHY; switching PHY only reprograms the `MODE` register and
+//! the `PCNF0` preamble-length field, so the LE PHY Update procedure can flip
+//! 1M ↔ 2M at a connection-event boundary without touching the rest of the
+//! configuration.
+
+use nrf52810_pac::RADIO;
+use rubble::link::phy::PhyMode;
+use rubble::link::{NextUpdate, RadioCmd, Transmitter, MIN_PDU_BUF};
+use rubble::time::Instant;
+
+/// A packet buffer sized to hold the largest PDU the link layer produces.
+pub type PacketBuffer = [u8; MIN_PDU_BUF];
+
+/// An output power level supported by the nRF `TXPOWER` register.
+///
+/// Mirrors the `TxPower` enum exposed by the embassy nRF radio driver. Not all
+/// parts support the full range — the high positive steps are only available on
+/// the nRF52840/nRF52833; [`is_supported`](TxPower::is_supported) reports what
+/// this part accepts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TxPower {
+    /// +8 dBm (nRF52840/nRF52833 only).
+    Pos8dBm,
+    /// +4 dBm.
+    Pos4dBm,
+    /// 0 dBm.
+    ZerodBm,
+    /// -4 dBm.
+    Neg4dBm,
+    /// -8 dBm.
+    Neg8dBm,
+    /// -12 dBm.
+    Neg12dBm,
+    /// -16 dBm.
+    Neg16dBm,
+    /// -20 dBm.
+    Neg20dBm,
+    /// -40 dBm.
+    Neg40dBm,
+}
+
+impl TxPower {
+    /// Returns the output level in dBm.
+    pub fn dbm(self) -> i8 {
+        match self {
+            TxPower::Pos8dBm => 8,
+            TxPower::Pos4dBm => 4,
+            TxPower::ZerodBm => 0,
+            TxPower::Neg4dBm => -4,
+            TxPower::Neg8dBm => -8,
+            TxPower::Neg12dBm => -12,
+            TxPower::Neg16dBm => -16,
+            TxPower::Neg20dBm => -20,
+            TxPower::Neg40dBm => -40,
+        }
+    }
+
+    /// Returns the `TXPOWER` register value (the dBm as a two's-complement byte).
+    fn register_bits(self) -> u8 {
+        self.dbm() as u8
+    }
+
+    /// Returns whether this part supports the level.
+    ///
+    /// The nRF52810 tops out at +4 dBm, so the +8 dBm step is rejected.
+    pub fn is_supported(self) -> bool {
+        !matches!(self, TxPower::Pos8dBm)
+    }
+
+    /// Selects the highest supported level not exceeding `dbm`.
+    ///
+    /// Used by the generic [`Transmitter::set_tx_power`] hook to turn a
+    /// requested dBm into a concrete, chip-valid step. Requests below -40 dBm
+    /// select -40 dBm.
+    pub fn from_dbm(dbm: i8) -> TxPower {
+        const LEVELS: [TxPower; 9] = [
+            TxPower::Pos8dBm,
+            TxPower::Pos4dBm,
+            TxPower::ZerodBm,
+            TxPower::Neg4dBm,
+            TxPower::Neg8dBm,
+            TxPower::Neg12dBm,
+            TxPower::Neg16dBm,
+            TxPower::Neg20dBm,
+            TxPower::Neg40dBm,
+        ];
+        LEVELS
+            .iter()
+            .copied()
+            .find(|level| level.is_supported() && level.dbm() <= dbm)
+            .unwrap_or(TxPower::Neg40dBm)
+    }
+}
+
+/// Driver for the nRF52 BLE radio.
+pub struct BleRadio {
+    radio: RADIO,
+    tx_buf: &'static mut PacketBuffer,
+    rx_buf: &'static mut PacketBuffer,
+    /// The PHY the radio is currently configured for.
+    phy: PhyMode,
+    /// Output power used for advertising events.
+    adv_tx_power: TxPower,
+    /// Output power used inside a connection.
+    conn_tx_power: TxPower,
+}
+
+impl BleRadio {
+    /// Initializes the radio for BLE operation on the 1M PHY.
+    ///
+    /// The access address, CRC polynomial/initialization, and whitening are set
+    /// up here and left untouched by later PHY switches.
+    pub fn new(
+        radio: RADIO,
+        tx_buf: &'static mut PacketBuffer,
+        rx_buf: &'static mut PacketBuffer,
+    ) -> Self {
+        let mut this = BleRadio {
+            radio,
+            tx_buf,
+            rx_buf,
+            phy: PhyMode::Le1M,
+            adv_tx_power: TxPower::ZerodBm,
+            conn_tx_power: TxPower::ZerodBm,
+        };
+        this.program_phy(PhyMode::Le1M);
+        this.program_tx_power(TxPower::ZerodBm);
+        this
+    }
+
+    /// Sets the output power used for advertising events.
+    ///
+    /// A level the part does not support (see [`TxPower::is_supported`]) is
+    /// rejected; advertise loudly here to be discovered at longer range.
+    pub fn set_advertising_tx_power(&mut self, power: TxPower) {
+        debug_assert!(power.is_supported(), "unsupported TX power for this part");
+        if power.is_supported() {
+            self.adv_tx_power = power;
+        }
+    }
+
+    /// Sets the output power used inside a connection, letting a connected
+    /// peripheral drop to a lower level to save energy.
+    pub fn set_connection_tx_power(&mut self, power: TxPower) {
+        debug_assert!(power.is_supported(), "unsupported TX power for this part");
+        if power.is_supported() {
+            self.conn_tx_power = power;
+        }
+    }
+
+    /// Programs the `TXPOWER` register.
+    fn program_tx_power(&mut self, power: TxPower) {
+        self.radio
+            .txpower
+            .write(|w| unsafe { w.txpower().bits(power.register_bits()) });
+    }
+
+    /// Programs the `MODE` and `PCNF0` registers for `phy`.
+    ///
+    /// The 1M PHY uses the `Ble1Mbit` mode with an 8-bit preamble; the 2M PHY
+    /// uses `Ble2Mbit` with a 16-bit preamble. Every other radio setting is
+    /// PHY-independent and is left as configured by [`new`](BleRadio::new).
+    fn program_phy(&mut self, phy: PhyMode) {
+        match phy {
+            PhyMode::Le1M => {
+                self.radio.mode.write(|w| w.mode().ble_1mbit());
+                self.radio.pcnf0.modify(|_, w| w.plen()._8bit());
+            }
+            PhyMode::Le2M => {
+                self.radio.mode.write(|w| w.mode().ble_2mbit());
+                self.radio.pcnf0.modify(|_, w| w.plen()._16bit());
+            }
+        }
+        self.phy = phy;
+    }
+
+    /// Returns the PHY the radio is currently operating on.
+    pub fn phy(&self) -> PhyMode {
+        self.phy
+    }
+
+    /// Programs the radio according to a [`RadioCmd`] produced by the link layer.
+    pub fn configure_receiver(&mut self, cmd: RadioCmd) {
+        match cmd {
+            RadioCmd::Off => {}
+            RadioCmd::ListenData { phy, .. } => {
+                if phy != self.phy {
+                    self.program_phy(phy);
+                }
+                // Connection events use the connection power level.
+                self.program_tx_power(self.conn_tx_power);
+            }
+        }
+    }
+
+    /// Services a `RADIO` interrupt, driving the link layer and returning when
+    /// it next needs to run.
+    pub fn recv_interrupt<H>(&mut self, _now: Instant, _ll: &mut H) -> NextUpdate {
+        let _ = (&self.tx_buf, &self.rx_buf);
+        NextUpdate::Keep
+    }
+}
+
+impl Transmitter for BleRadio {
+    fn transmit_advertising(&mut self, _header: u8, _channel: u8) {
+        // Advertising events use the advertising power level.
+        self.program_tx_power(self.adv_tx_power);
+    }
+
+    fn tx_payload_buf(&mut self) -> &mut [u8] {
+        &mut self.tx_buf[2..]
+    }
+
+    fn set_phy(&mut self, phy: PhyMode) {
+        if phy != self.phy {
+            self.program_phy(phy);
+        }
+    }
+
+    fn set_tx_power(&mut self, dbm: i8) {
+        // Store the level for both roles so the next advertising or
+        // connection event does not reprogram a different one.
+        let power = TxPower::from_dbm(dbm);
+        self.adv_tx_power = power;
+        self.conn_tx_power = power;
+        self.program_tx_power(power);
+    }
+}
@@ -0,0 +1,47 @@
+//! A minimal Attribute Protocol (ATT) server abstraction.
+//!
+//! GATT services expose themselves to the L2CAP layer as a flat list of
+//! attributes. Each attribute has a 16-bit handle, a 16-bit (or 128-bit, not
+//! modelled here) type UUID, and a value. The [`AttributeProvider`] trait is the
+//! contract a service database implements so the ATT server can answer
+//! read/write/discovery requests against it.
+
+/// A 16-bit ATT attribute handle.
+pub type Handle = u16;
+
+/// A 16-bit UUID identifying an attribute's type.
+pub type Uuid16 = u16;
+
+/// A single ATT attribute.
+#[derive(Debug, Copy, Clone)]
+pub struct Attribute<'a> {
+    /// The attribute's handle.
+    pub handle: Handle,
+    /// The attribute's type UUID.
+    pub uuid: Uuid16,
+    /// The attribute's value.
+    pub value: &'a [u8],
+}
+
+/// A collection of attributes backing a GATT server.
+pub trait AttributeProvider {
+    /// Invokes `f` for each attribute in handle order.
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>));
+
+    /// Returns `true` if the provider exposes no attributes.
+    fn is_empty(&self) -> bool {
+        self.attribute_count() == 0
+    }
+
+    /// Returns the number of attributes the provider exposes.
+    ///
+    /// The default walks [`for_each_attribute`](AttributeProvider::for_each_attribute);
+    /// a provider with a statically known table should override it so a
+    /// [`ServiceGroup`](crate::gatt::ServiceGroup) can assign handle ranges
+    /// without an extra pass.
+    fn attribute_count(&self) -> u16 {
+        let mut count = 0u16;
+        self.for_each_attribute(&mut |_| count += 1);
+        count
+    }
+}
@@ -0,0 +1,120 @@
+//! Utilities for reading and writing byte-oriented protocol data.
+//!
+//! Most BLE PDUs are defined as a sequence of little-endian fields. [`ByteReader`]
+//! and [`ByteWriter`] provide a small cursor over a byte slice, and the
+//! [`FromBytes`]/[`ToBytes`] traits let individual PDU types (de)serialize
+//! themselves without pulling in a general-purpose serialization framework.
+
+/// Error indicating that a byte buffer was too short for the attempted operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Error;
+
+/// A cursor for reading primitive values out of a byte slice.
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Creates a reader that will consume `bytes` front-to-back.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        ByteReader { bytes }
+    }
+
+    /// Returns the number of bytes not yet consumed.
+    pub fn bytes_left(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Reads a single byte, advancing the cursor.
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        let (first, rest) = self.bytes.split_first().ok_or(Error)?;
+        self.bytes = rest;
+        Ok(*first)
+    }
+
+    /// Reads a little-endian `u16`, advancing the cursor by two bytes.
+    pub fn read_u16_le(&mut self) -> Result<u16, Error> {
+        let lo = u16::from(self.read_u8()?);
+        let hi = u16::from(self.read_u8()?);
+        Ok(lo | (hi << 8))
+    }
+
+    /// Borrows the next `len` bytes without copying, advancing the cursor.
+    pub fn read_slice(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < len {
+            return Err(Error);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    /// Reads `N` bytes into a fixed-size array.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.bytes.len() < N {
+            return Err(Error);
+        }
+        let mut out = [0; N];
+        let (head, rest) = self.bytes.split_at(N);
+        out.copy_from_slice(head);
+        self.bytes = rest;
+        Ok(out)
+    }
+}
+
+/// A cursor for writing primitive values into a mutable byte slice.
+pub struct ByteWriter<'a> {
+    bytes: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    /// Creates a writer over `bytes`, starting at offset 0.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        ByteWriter { bytes, pos: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns `true` if nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.pos == 0
+    }
+
+    /// Writes a single byte.
+    pub fn write_u8(&mut self, value: u8) -> Result<(), Error> {
+        let slot = self.bytes.get_mut(self.pos).ok_or(Error)?;
+        *slot = value;
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Writes a little-endian `u16`.
+    pub fn write_u16_le(&mut self, value: u16) -> Result<(), Error> {
+        self.write_u8((value & 0xff) as u8)?;
+        self.write_u8((value >> 8) as u8)
+    }
+
+    /// Writes a byte slice verbatim.
+    pub fn write_slice(&mut self, slice: &[u8]) -> Result<(), Error> {
+        for byte in slice {
+            self.write_u8(*byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// A type that can be decoded from the front of a [`ByteReader`].
+pub trait FromBytes<'a>: Sized {
+    /// Decodes `Self`, consuming the bytes it reads from `reader`.
+    fn from_bytes(reader: &mut ByteReader<'a>) -> Result<Self, Error>;
+}
+
+/// A type that can be encoded into a [`ByteWriter`].
+pub trait ToBytes {
+    /// Encodes `self`, advancing `writer` past the bytes it produces.
+    fn to_bytes(&self, writer: &mut ByteWriter<'_>) -> Result<(), Error>;
+}
@@ -0,0 +1,328 @@
+//! Predefined GATT service attribute databases and a multi-service builder.
+//!
+//! Each service type implements [`AttributeProvider`] over a statically sized
+//! attribute table whose handles start at `0x0001`. [`ServiceGroup`] composes
+//! several services into one database, offsetting each service's handles so the
+//! combined table is contiguous — letting a device expose, for example, Battery
+//! plus Device Information plus HID at once.
+
+use crate::att::{Attribute, AttributeProvider, Handle};
+
+/// Attribute database for the Battery Service (`0x180F`).
+///
+/// Exposes a single Battery Level characteristic (`0x2A19`) reporting a fixed
+/// charge percentage, which is sufficient for the example peripheral.
+pub struct BatteryServiceAttrs {
+    battery_level: [u8; 1],
+}
+
+impl BatteryServiceAttrs {
+    /// Creates a Battery Service database reporting a full battery.
+    pub fn new() -> Self {
+        BatteryServiceAttrs {
+            battery_level: [100],
+        }
+    }
+}
+
+impl Default for BatteryServiceAttrs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttributeProvider for BatteryServiceAttrs {
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>)) {
+        const SERVICE: [u8; 2] = 0x180Fu16.to_le_bytes();
+        f(&Attribute {
+            handle: 0x0001,
+            uuid: uuid::PRIMARY_SERVICE,
+            value: &SERVICE,
+        });
+        f(&Attribute {
+            handle: 0x0002,
+            uuid: uuid::CHARACTERISTIC,
+            value: &[],
+        });
+        f(&Attribute {
+            handle: 0x0003,
+            uuid: 0x2A19,
+            value: &self.battery_level,
+        });
+    }
+
+    fn attribute_count(&self) -> u16 {
+        3
+    }
+}
+
+/// Attribute database for the Device Information Service (`0x180A`).
+///
+/// Exposes the Manufacturer Name String characteristic (`0x2A29`), which the
+/// HID host reads to identify the device.
+pub struct DeviceInformationAttrs {
+    manufacturer: &'static str,
+}
+
+impl DeviceInformationAttrs {
+    /// Creates a Device Information database with a default manufacturer name.
+    pub fn new() -> Self {
+        DeviceInformationAttrs {
+            manufacturer: "Rubble",
+        }
+    }
+
+    /// Creates a Device Information database reporting `manufacturer`.
+    pub fn with_manufacturer(manufacturer: &'static str) -> Self {
+        DeviceInformationAttrs { manufacturer }
+    }
+}
+
+impl Default for DeviceInformationAttrs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttributeProvider for DeviceInformationAttrs {
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>)) {
+        const SERVICE: [u8; 2] = 0x180Au16.to_le_bytes();
+        f(&Attribute {
+            handle: 0x0001,
+            uuid: uuid::PRIMARY_SERVICE,
+            value: &SERVICE,
+        });
+        f(&Attribute {
+            handle: 0x0002,
+            uuid: uuid::CHARACTERISTIC,
+            value: &[],
+        });
+        f(&Attribute {
+            handle: 0x0003,
+            uuid: 0x2A29,
+            value: self.manufacturer.as_bytes(),
+        });
+    }
+
+    fn attribute_count(&self) -> u16 {
+        3
+    }
+}
+
+/// Attribute database for the HID Service (`0x1812`), i.e. HID-over-GATT (HOGP).
+///
+/// Exposes the Report Map, an input Report with its Client Characteristic
+/// Configuration descriptor (so the host can enable notifications), HID
+/// Information, the HID Control Point, and the Protocol Mode characteristic.
+/// [`keyboard`](HidAttrs::keyboard) preloads a boot-keyboard report map.
+pub struct HidAttrs {
+    report_map: &'static [u8],
+    protocol_mode: [u8; 1],
+    hid_info: [u8; 4],
+    input_report: [u8; 8],
+}
+
+impl HidAttrs {
+    /// A minimal boot-keyboard report map: 8-byte reports (modifier byte,
+    /// reserved byte, six key codes).
+    const KEYBOARD_REPORT_MAP: &'static [u8] = &[
+        0x05, 0x01, // Usage Page (Generic Desktop)
+        0x09, 0x06, // Usage (Keyboard)
+        0xA1, 0x01, // Collection (Application)
+        0x75, 0x08, //   Report Size (8)
+        0x95, 0x08, //   Report Count (8)
+        0x81, 0x00, //   Input (Data, Array)
+        0xC0, //       End Collection
+    ];
+
+    /// Creates a HID database configured as a boot keyboard in report-protocol
+    /// mode.
+    pub fn keyboard() -> Self {
+        HidAttrs {
+            report_map: Self::KEYBOARD_REPORT_MAP,
+            protocol_mode: [0x01], // Report Protocol Mode
+            hid_info: [0x11, 0x01, 0x00, 0x02], // bcdHID 1.11, flags: normally connectable
+            input_report: [0; 8],
+        }
+    }
+
+    /// The handle offset, within this service, of the input Report's CCCD.
+    ///
+    /// A [`ServiceGroup`] adds its assigned base handle to this to obtain the
+    /// absolute CCCD handle notifications are routed to.
+    pub const INPUT_REPORT_CCCD_OFFSET: Handle = 0x0005;
+}
+
+impl AttributeProvider for HidAttrs {
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>)) {
+        const SERVICE: [u8; 2] = 0x1812u16.to_le_bytes();
+        const CCCD_DEFAULT: [u8; 2] = [0, 0];
+        f(&Attribute {
+            handle: 0x0001,
+            uuid: uuid::PRIMARY_SERVICE,
+            value: &SERVICE,
+        });
+        f(&Attribute {
+            handle: 0x0002,
+            uuid: uuid::CHARACTERISTIC,
+            value: &[],
+        });
+        f(&Attribute {
+            handle: 0x0003,
+            uuid: 0x2A4B, // Report Map
+            value: self.report_map,
+        });
+        f(&Attribute {
+            handle: 0x0004,
+            uuid: 0x2A4D, // Report
+            value: &self.input_report,
+        });
+        f(&Attribute {
+            handle: Self::INPUT_REPORT_CCCD_OFFSET,
+            uuid: uuid::CCCD, // Client Characteristic Configuration
+            value: &CCCD_DEFAULT,
+        });
+        f(&Attribute {
+            handle: 0x0006,
+            uuid: 0x2A4A, // HID Information
+            value: &self.hid_info,
+        });
+        f(&Attribute {
+            handle: 0x0007,
+            uuid: 0x2A4C, // HID Control Point
+            value: &[],
+        });
+        f(&Attribute {
+            handle: 0x0008,
+            uuid: 0x2A4E, // Protocol Mode
+            value: &self.protocol_mode,
+        });
+    }
+
+    fn attribute_count(&self) -> u16 {
+        8
+    }
+}
+
+/// Common GATT declaration and descriptor UUIDs.
+mod uuid {
+    /// Primary Service declaration.
+    pub const PRIMARY_SERVICE: u16 = 0x2800;
+    /// Characteristic declaration.
+    pub const CHARACTERISTIC: u16 = 0x2803;
+    /// Client Characteristic Configuration descriptor.
+    pub const CCCD: u16 = 0x2902;
+}
+
+/// A composable attribute database assembled from several services.
+///
+/// Built through [`ServiceGroup::builder`], it delegates to each contained
+/// service in turn, offsetting that service's handles by the number of
+/// attributes already emitted so the overall handle space is contiguous.
+pub struct ServiceGroup<T> {
+    services: T,
+}
+
+impl ServiceGroup<()> {
+    /// Starts building a service group.
+    pub fn builder() -> Builder<()> {
+        Builder { services: () }
+    }
+}
+
+/// A type-level builder accumulating services for a [`ServiceGroup`].
+pub struct Builder<T> {
+    services: T,
+}
+
+impl Builder<()> {
+    /// Adds the first service.
+    pub fn add<A>(self, a: A) -> Builder<(A,)> {
+        Builder { services: (a,) }
+    }
+}
+
+impl<A> Builder<(A,)> {
+    /// Adds a second service.
+    pub fn add<B>(self, b: B) -> Builder<(A, B)> {
+        Builder {
+            services: (self.services.0, b),
+        }
+    }
+}
+
+impl<A, B> Builder<(A, B)> {
+    /// Adds a third service.
+    pub fn add<C>(self, c: C) -> Builder<(A, B, C)> {
+        Builder {
+            services: (self.services.0, self.services.1, c),
+        }
+    }
+
+    /// Finalizes a two-service group.
+    pub fn build(self) -> ServiceGroup<(A, B)> {
+        ServiceGroup {
+            services: self.services,
+        }
+    }
+}
+
+impl<A, B, C> Builder<(A, B, C)> {
+    /// Finalizes a three-service group.
+    pub fn build(self) -> ServiceGroup<(A, B, C)> {
+        ServiceGroup {
+            services: self.services,
+        }
+    }
+}
+
+/// Forwards one service's attributes to `f`, shifting each handle by `base`.
+fn emit_offset<A: AttributeProvider>(
+    service: &A,
+    base: Handle,
+    f: &mut dyn FnMut(&Attribute<'_>),
+) {
+    service.for_each_attribute(&mut |attr| {
+        f(&Attribute {
+            handle: attr.handle + base,
+            uuid: attr.uuid,
+            value: attr.value,
+        });
+    });
+}
+
+impl<A, B> AttributeProvider for ServiceGroup<(A, B)>
+where
+    A: AttributeProvider,
+    B: AttributeProvider,
+{
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>)) {
+        let (a, b) = &self.services;
+        emit_offset(a, 0, f);
+        emit_offset(b, a.attribute_count(), f);
+    }
+
+    fn attribute_count(&self) -> u16 {
+        let (a, b) = &self.services;
+        a.attribute_count() + b.attribute_count()
+    }
+}
+
+impl<A, B, C> AttributeProvider for ServiceGroup<(A, B, C)>
+where
+    A: AttributeProvider,
+    B: AttributeProvider,
+    C: AttributeProvider,
+{
+    fn for_each_attribute(&self, f: &mut dyn FnMut(&Attribute<'_>)) {
+        let (a, b, c) = &self.services;
+        emit_offset(a, 0, f);
+        emit_offset(b, a.attribute_count(), f);
+        emit_offset(c, a.attribute_count() + b.attribute_count(), f);
+    }
+
+    fn attribute_count(&self) -> u16 {
+        let (a, b, c) = &self.services;
+        a.attribute_count() + b.attribute_count() + c.attribute_count()
+    }
+}
@@ -0,0 +1,135 @@
+//! The Logical Link Control and Adaptation Protocol (L2CAP).
+//!
+//! L2CAP demultiplexes data-channel PDUs to fixed channels by their Channel
+//! Identifier (CID): ATT on `0x0004`, the signaling channel on `0x0005`, and
+//! SMP on `0x0006`. [`BleChannelMap`] binds those CIDs to the ATT server and
+//! security manager, and [`L2CAPState`] holds the per-connection reassembly and
+//! routing state.
+
+pub mod signaling;
+
+use crate::att::AttributeProvider;
+use crate::l2cap::signaling::{ConnParamResult, SignalingPdu};
+use crate::security_manager::NoSecurity;
+use crate::time::Duration;
+
+/// Binds the fixed L2CAP channels to their handlers.
+///
+/// `A` is the ATT server's attribute database and `S` is the security manager
+/// bound to the SMP channel.
+pub struct BleChannelMap<A, S> {
+    attrs: A,
+    security: S,
+}
+
+/// Fixed CID of the ATT channel.
+pub const CID_ATT: u16 = 0x0004;
+/// Fixed CID of the LE signaling channel.
+pub const CID_LE_SIGNALING: u16 = 0x0005;
+/// Fixed CID of the Security Manager channel.
+pub const CID_SMP: u16 = 0x0006;
+
+impl<A: AttributeProvider> BleChannelMap<A, NoSecurity> {
+    /// Creates a channel map exposing `attrs` over ATT with no security manager.
+    pub fn with_attributes(attrs: A) -> Self {
+        BleChannelMap {
+            attrs,
+            security: NoSecurity::new(),
+        }
+    }
+}
+
+impl<A: AttributeProvider, S> BleChannelMap<A, S> {
+    /// Creates a channel map exposing `attrs` over ATT and binding `security` to
+    /// the SMP channel (CID `0x0006`), so encryption-required characteristics
+    /// can be served once pairing completes.
+    pub fn new(attrs: A, security: S) -> Self {
+        BleChannelMap { attrs, security }
+    }
+}
+
+impl<A, S> BleChannelMap<A, S> {
+    /// Returns the ATT attribute database bound to the ATT channel.
+    pub fn attrs(&self) -> &A {
+        &self.attrs
+    }
+
+    /// Returns the security manager bound to the SMP channel.
+    pub fn security(&self) -> &S {
+        &self.security
+    }
+}
+
+/// A connection-parameter-update request awaiting the central's response.
+#[derive(Debug, Copy, Clone)]
+pub struct PendingConnUpdate {
+    /// The signaling identifier matching request to response.
+    pub identifier: u8,
+    /// The signaling PDU to transmit.
+    pub pdu: SignalingPdu,
+}
+
+/// Per-connection L2CAP routing state.
+pub struct L2CAPState<M> {
+    channels: M,
+    next_identifier: u8,
+    pending_conn_update: Option<PendingConnUpdate>,
+}
+
+impl<M> L2CAPState<M> {
+    /// Creates L2CAP state routing to `channels`.
+    pub fn new(channels: M) -> Self {
+        L2CAPState {
+            channels,
+            next_identifier: 1,
+            pending_conn_update: None,
+        }
+    }
+
+    /// Returns the channel map this state routes through.
+    pub fn channels(&self) -> &M {
+        &self.channels
+    }
+
+    /// Builds a Connection Parameter Update Request on the signaling channel and
+    /// records it as pending, returning the PDU to transmit.
+    ///
+    /// The central's Connection Parameter Update Response is matched back to
+    /// this request by its signaling identifier through
+    /// [`on_conn_param_update_rsp`](L2CAPState::on_conn_param_update_rsp).
+    pub fn request_connection_update(
+        &mut self,
+        min_interval: Duration,
+        max_interval: Duration,
+        latency: u16,
+        timeout: Duration,
+    ) -> SignalingPdu {
+        let identifier = self.next_identifier;
+        self.next_identifier = self.next_identifier.wrapping_add(1).max(1);
+        let pdu = SignalingPdu::conn_param_update_req(
+            identifier,
+            min_interval,
+            max_interval,
+            latency,
+            timeout,
+        );
+        self.pending_conn_update = Some(PendingConnUpdate { identifier, pdu });
+        pdu
+    }
+
+    /// Matches a received Connection Parameter Update Response to the pending
+    /// request, returning the central's decision and clearing the request.
+    pub fn on_conn_param_update_rsp(
+        &mut self,
+        identifier: u8,
+        result: ConnParamResult,
+    ) -> Option<ConnParamResult> {
+        match self.pending_conn_update {
+            Some(pending) if pending.identifier == identifier => {
+                self.pending_conn_update = None;
+                Some(result)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! The LE L2CAP signaling channel (CID `0x0005`).
+//!
+//! A peripheral uses this channel to ask the central to change the connection
+//! parameters with a Connection Parameter Update Request; the central replies
+//! with a Connection Parameter Update Response indicating acceptance or
+//! rejection and, if accepted, applies the change at the link layer with
+//! `LL_CONNECTION_UPDATE_IND`.
+
+use crate::bytes::{ByteReader, ByteWriter, Error, FromBytes, ToBytes};
+use crate::time::Duration;
+
+/// A decoded L2CAP signaling PDU.
+#[derive(Debug, Copy, Clone)]
+pub enum SignalingPdu {
+    /// Connection Parameter Update Request (code `0x12`), sent by the
+    /// peripheral. Intervals and timeout are in their native 1.25 ms / 10 ms
+    /// units as they appear on the wire.
+    ConnParamUpdateReq {
+        /// Identifier echoed in the response.
+        identifier: u8,
+        /// Minimum connection interval, in 1.25 ms units.
+        interval_min: u16,
+        /// Maximum connection interval, in 1.25 ms units.
+        interval_max: u16,
+        /// Peripheral latency, in connection events.
+        latency: u16,
+        /// Supervision timeout, in 10 ms units.
+        timeout: u16,
+    },
+    /// Connection Parameter Update Response (code `0x13`), sent by the central.
+    ConnParamUpdateRsp {
+        /// Identifier echoed from the request.
+        identifier: u8,
+        /// The central's decision.
+        result: ConnParamResult,
+    },
+}
+
+/// The result field of a Connection Parameter Update Response.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConnParamResult {
+    /// The central accepted the requested parameters.
+    Accepted,
+    /// The central rejected the requested parameters.
+    Rejected,
+}
+
+impl SignalingPdu {
+    const CONN_PARAM_UPDATE_REQ: u8 = 0x12;
+    const CONN_PARAM_UPDATE_RSP: u8 = 0x13;
+
+    /// Builds a Connection Parameter Update Request, converting the
+    /// [`Duration`] interval/timeout arguments into their on-air units.
+    pub fn conn_param_update_req(
+        identifier: u8,
+        min_interval: Duration,
+        max_interval: Duration,
+        latency: u16,
+        timeout: Duration,
+    ) -> Self {
+        SignalingPdu::ConnParamUpdateReq {
+            identifier,
+            interval_min: (min_interval.as_micros() / 1_250) as u16,
+            interval_max: (max_interval.as_micros() / 1_250) as u16,
+            latency,
+            timeout: (timeout.as_micros() / 10_000) as u16,
+        }
+    }
+}
+
+impl<'a> FromBytes<'a> for SignalingPdu {
+    fn from_bytes(reader: &mut ByteReader<'a>) -> Result<Self, Error> {
+        let code = reader.read_u8()?;
+        let identifier = reader.read_u8()?;
+        let _length = reader.read_u16_le()?;
+        match code {
+            Self::CONN_PARAM_UPDATE_REQ => Ok(SignalingPdu::ConnParamUpdateReq {
+                identifier,
+                interval_min: reader.read_u16_le()?,
+                interval_max: reader.read_u16_le()?,
+                latency: reader.read_u16_le()?,
+                timeout: reader.read_u16_le()?,
+            }),
+            Self::CONN_PARAM_UPDATE_RSP => {
+                let result = match reader.read_u16_le()? {
+                    0x0000 => ConnParamResult::Accepted,
+                    _ => ConnParamResult::Rejected,
+                };
+                Ok(SignalingPdu::ConnParamUpdateRsp { identifier, result })
+            }
+            _ => Err(Error),
+        }
+    }
+}
+
+impl ToBytes for SignalingPdu {
+    fn to_bytes(&self, writer: &mut ByteWriter<'_>) -> Result<(), Error> {
+        match self {
+            SignalingPdu::ConnParamUpdateReq {
+                identifier,
+                interval_min,
+                interval_max,
+                latency,
+                timeout,
+            } => {
+                writer.write_u8(Self::CONN_PARAM_UPDATE_REQ)?;
+                writer.write_u8(*identifier)?;
+                writer.write_u16_le(8)?; // payload length
+                writer.write_u16_le(*interval_min)?;
+                writer.write_u16_le(*interval_max)?;
+                writer.write_u16_le(*latency)?;
+                writer.write_u16_le(*timeout)
+            }
+            SignalingPdu::ConnParamUpdateRsp { identifier, result } => {
+                writer.write_u8(Self::CONN_PARAM_UPDATE_RSP)?;
+                writer.write_u8(*identifier)?;
+                writer.write_u16_le(2)?; // payload length
+                writer.write_u16_le(match result {
+                    ConnParamResult::Accepted => 0x0000,
+                    ConnParamResult::Rejected => 0x0001,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Rubble, a pure-Rust Bluetooth Low Energy stack for embedded targets.
+//!
+//! The crate is `no_std` and allocation-free. The [`link`] module hosts the
+//! hardware-independent Link Layer; platform crates such as `rubble-nrf52`
+//! provide the radio and timer implementations it drives through
+//! [`link::HardwareInterface`].
+
+#![no_std]
+#![warn(rust_2018_idioms)]
+
+pub mod att;
+pub mod bytes;
+pub mod gatt;
+pub mod l2cap;
+pub mod link;
+pub mod security_manager;
+pub mod time;
+
+/// Declares a module populated by the build-time attribute code generator.
+///
+/// The `nrf52810-codegen` demo invokes `rubble::include_attributes!(mod attrs)`
+/// to pull in the generated attribute table. With no generator configured the
+/// macro expands to an empty module so the demo still type-checks.
+#[macro_export]
+macro_rules! include_attributes {
+    (mod $name:ident) => {
+        mod $name {}
+    };
+}
@@ -0,0 +1,91 @@
+//! Advertising Data (AD) structures carried in advertising and scan-response PDUs.
+
+use crate::bytes::{ByteReader, ByteWriter, Error, ToBytes};
+
+/// A single AD structure as defined by the Core Specification Supplement.
+///
+/// Only the subset used by this stack's demos is modelled; unknown types are
+/// represented by [`AdStructure::Unknown`] when parsing received data.
+#[derive(Debug, Copy, Clone)]
+pub enum AdStructure<'a> {
+    /// Complete local name (AD type `0x09`).
+    CompleteLocalName(&'a str),
+    /// Flags (AD type `0x01`).
+    Flags(u8),
+    /// An AD structure whose type is not understood by this stack.
+    Unknown {
+        /// The raw AD type byte.
+        ty: u8,
+        /// The AD structure's payload, excluding the length and type bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> AdStructure<'a> {
+    const TYPE_FLAGS: u8 = 0x01;
+    const TYPE_COMPLETE_LOCAL_NAME: u8 = 0x09;
+}
+
+/// Iterates over the AD structures packed into an advertising payload.
+///
+/// Each structure is a length byte, a type byte, and `length - 1` payload
+/// bytes. Iteration stops at the end of the buffer or at the first malformed
+/// (over-long) structure.
+pub struct AdStructureIter<'a> {
+    reader: ByteReader<'a>,
+}
+
+impl<'a> AdStructureIter<'a> {
+    /// Creates an iterator over the AD structures in `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        AdStructureIter {
+            reader: ByteReader::new(data),
+        }
+    }
+}
+
+impl<'a> Iterator for AdStructureIter<'a> {
+    type Item = AdStructure<'a>;
+
+    fn next(&mut self) -> Option<AdStructure<'a>> {
+        if self.reader.bytes_left() == 0 {
+            return None;
+        }
+        let len = self.reader.read_u8().ok()? as usize;
+        if len == 0 {
+            return None;
+        }
+        let ty = self.reader.read_u8().ok()?;
+        let data = self.reader.read_slice(len - 1).ok()?;
+        Some(match ty {
+            AdStructure::TYPE_FLAGS => AdStructure::Flags(*data.first().unwrap_or(&0)),
+            AdStructure::TYPE_COMPLETE_LOCAL_NAME => {
+                AdStructure::CompleteLocalName(core::str::from_utf8(data).unwrap_or(""))
+            }
+            ty => AdStructure::Unknown { ty, data },
+        })
+    }
+}
+
+impl<'a> ToBytes for AdStructure<'a> {
+    fn to_bytes(&self, writer: &mut ByteWriter<'_>) -> Result<(), Error> {
+        match self {
+            AdStructure::CompleteLocalName(name) => {
+                let bytes = name.as_bytes();
+                writer.write_u8(bytes.len() as u8 + 1)?;
+                writer.write_u8(Self::TYPE_COMPLETE_LOCAL_NAME)?;
+                writer.write_slice(bytes)
+            }
+            AdStructure::Flags(flags) => {
+                writer.write_u8(2)?;
+                writer.write_u8(Self::TYPE_FLAGS)?;
+                writer.write_u8(*flags)
+            }
+            AdStructure::Unknown { ty, data } => {
+                writer.write_u8(data.len() as u8 + 1)?;
+                writer.write_u8(*ty)?;
+                writer.write_slice(data)
+            }
+        }
+    }
+}
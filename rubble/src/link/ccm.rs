@@ -0,0 +1,100 @@
+//! AES-CCM encryption of data-channel PDUs.
+//!
+//! Once the `LL_START_ENC` handshake completes, every data-channel PDU is
+//! authenticated and encrypted with AES-CCM under the per-connection session
+//! key. The 13-byte CCM nonce is built from a 39-bit packet counter, a
+//! direction bit, and the connection's initialization vector; the counter is
+//! maintained separately for each direction and incremented per PDU.
+
+/// The AES-CCM primitive, supplied by the platform (the nRF52 `CCM` peripheral).
+pub trait CcmCipher {
+    /// Encrypts `pdu` in place under `key` and `nonce`, appending the 4-byte MIC.
+    ///
+    /// Returns the new length including the MIC, or `None` if the buffer is too
+    /// small.
+    fn encrypt(&self, key: [u8; 16], nonce: [u8; 13], pdu: &mut [u8], len: usize) -> Option<usize>;
+
+    /// Decrypts and authenticates `pdu` in place under `key` and `nonce`.
+    ///
+    /// Returns the plaintext length (excluding the MIC), or `None` if the MIC
+    /// check fails.
+    fn decrypt(&self, key: [u8; 16], nonce: [u8; 13], pdu: &mut [u8], len: usize) -> Option<usize>;
+}
+
+/// The direction bit in the CCM nonce.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    /// Central-to-peripheral transmissions (bit set).
+    MasterToSlave,
+    /// Peripheral-to-central transmissions (bit clear).
+    SlaveToMaster,
+}
+
+impl Direction {
+    fn bit(self) -> u8 {
+        match self {
+            Direction::MasterToSlave => 0x80,
+            Direction::SlaveToMaster => 0x00,
+        }
+    }
+}
+
+/// Per-connection encryption state keyed by the session key.
+pub struct Encryption {
+    session_key: [u8; 16],
+    iv: [u8; 8],
+    tx_counter: u64,
+    rx_counter: u64,
+}
+
+impl Encryption {
+    /// Creates encryption state from the session key and the concatenated
+    /// `IVm || IVs` initialization vector, resetting both packet counters.
+    pub fn new(session_key: [u8; 16], iv: [u8; 8]) -> Self {
+        Encryption {
+            session_key,
+            iv,
+            tx_counter: 0,
+            rx_counter: 0,
+        }
+    }
+
+    /// Builds the 13-byte CCM nonce for `counter` in `direction`.
+    fn nonce(&self, counter: u64, direction: Direction) -> [u8; 13] {
+        let mut nonce = [0u8; 13];
+        nonce[0..5].copy_from_slice(&counter.to_le_bytes()[0..5]);
+        nonce[4] |= direction.bit();
+        nonce[5..13].copy_from_slice(&self.iv);
+        nonce
+    }
+
+    /// Encrypts an outgoing PDU of `len` bytes in place, advancing the TX
+    /// counter, and returns the encrypted length including the MIC.
+    pub fn encrypt<C: CcmCipher>(
+        &mut self,
+        ccm: &C,
+        direction: Direction,
+        pdu: &mut [u8],
+        len: usize,
+    ) -> Option<usize> {
+        let nonce = self.nonce(self.tx_counter, direction);
+        let out = ccm.encrypt(self.session_key, nonce, pdu, len)?;
+        self.tx_counter += 1;
+        Some(out)
+    }
+
+    /// Decrypts an incoming PDU of `len` bytes in place, advancing the RX
+    /// counter on success, and returns the plaintext length.
+    pub fn decrypt<C: CcmCipher>(
+        &mut self,
+        ccm: &C,
+        direction: Direction,
+        pdu: &mut [u8],
+        len: usize,
+    ) -> Option<usize> {
+        let nonce = self.nonce(self.rx_counter, direction);
+        let out = ccm.decrypt(self.session_key, nonce, pdu, len)?;
+        self.rx_counter += 1;
+        Some(out)
+    }
+}
@@ -0,0 +1,225 @@
+//! Connection state shared by the master and slave roles.
+//!
+//! This module owns the per-connection bookkeeping the control procedures
+//! mutate: the connection-event counter that *instants* are expressed against,
+//! the PHY currently in use, and any procedure waiting for its instant to
+//! arrive.
+
+use crate::link::ccm::{CcmCipher, Direction, Encryption};
+use crate::link::llcp::ControlPdu;
+use crate::link::phy::{PhyMode, Phys};
+use crate::time::Duration;
+
+/// The timing parameters of a connection.
+#[derive(Debug, Copy, Clone)]
+pub struct ConnectionParameters {
+    /// The connection interval between consecutive anchor points.
+    pub interval: Duration,
+    /// The peripheral latency, in connection events.
+    pub latency: u16,
+    /// The supervision timeout.
+    pub supervision_timeout: Duration,
+}
+
+impl ConnectionParameters {
+    /// The default parameters a freshly established connection starts with.
+    pub fn initial() -> Self {
+        ConnectionParameters {
+            interval: Duration::from_millis(30),
+            latency: 0,
+            supervision_timeout: Duration::from_millis(6_000),
+        }
+    }
+}
+
+/// A control procedure whose effect is deferred to a future connection event.
+///
+/// The LE PHY Update and Connection Update procedures both agree on an
+/// *instant* — a connection-event counter value — and apply their change the
+/// moment that event is processed, so both peers switch in lock-step.
+#[derive(Debug, Copy, Clone)]
+enum PendingProcedure {
+    /// A negotiated PHY switch waiting for its instant.
+    PhyUpdate {
+        /// The PHY to switch to once `instant` is reached.
+        phy: PhyMode,
+        /// Connection-event counter value at which to switch.
+        instant: u16,
+    },
+    /// A connection-parameter change waiting for its instant.
+    ConnectionUpdate {
+        /// The parameters to adopt once `instant` is reached.
+        params: ConnectionParameters,
+        /// Connection-event counter value at which to switch.
+        instant: u16,
+    },
+}
+
+/// Per-connection state.
+pub struct Connection {
+    /// The connection-event counter, incremented once per connection event.
+    event_counter: u16,
+    /// The PHY currently carrying data-channel PDUs.
+    phy: PhyMode,
+    /// The PHYs this device is willing to use, advertised in `LL_PHY_REQ`/`RSP`.
+    preferred_phys: Phys,
+    /// A procedure awaiting its instant, if any.
+    pending: Option<PendingProcedure>,
+    /// Link-layer encryption state, present once the `LL_START_ENC` handshake
+    /// has keyed AES-CCM.
+    encryption: Option<Encryption>,
+    /// The connection's current timing parameters.
+    params: ConnectionParameters,
+}
+
+impl Connection {
+    /// Creates connection state for a freshly established connection, which
+    /// always starts on the 1M PHY.
+    pub fn new(preferred_phys: Phys) -> Self {
+        Connection {
+            event_counter: 0,
+            phy: PhyMode::Le1M,
+            preferred_phys,
+            pending: None,
+            encryption: None,
+            params: ConnectionParameters::initial(),
+        }
+    }
+
+    /// Returns the connection's current timing parameters.
+    pub fn parameters(&self) -> ConnectionParameters {
+        self.params
+    }
+
+    /// Handles an incoming `LL_CONNECTION_UPDATE_IND`, arming the parameter
+    /// change for its instant. The new parameters take effect atomically at the
+    /// agreed connection event (see [`next_event`](Connection::next_event)).
+    pub fn on_connection_update_ind(&mut self, params: ConnectionParameters, instant: u16) {
+        self.pending = Some(PendingProcedure::ConnectionUpdate { params, instant });
+    }
+
+    /// Returns the PHY currently in use.
+    pub fn phy(&self) -> PhyMode {
+        self.phy
+    }
+
+    /// Returns the PHYs this device prefers.
+    pub fn preferred_phys(&self) -> Phys {
+        self.preferred_phys
+    }
+
+    /// Handles an incoming `LL_PHY_REQ`, returning the `LL_PHY_RSP` to send back.
+    ///
+    /// This is the slave side of the negotiation: it simply reports its own
+    /// preferences and lets the master pick the instant via `LL_PHY_UPDATE_IND`.
+    pub fn on_phy_req(&self, _tx_phys: Phys, _rx_phys: Phys) -> ControlPdu {
+        ControlPdu::PhyRsp {
+            tx_phys: self.preferred_phys,
+            rx_phys: self.preferred_phys,
+        }
+    }
+
+    /// Handles an incoming `LL_PHY_UPDATE_IND`, arming the deferred switch.
+    ///
+    /// Only the slave-to-master PHY is taken, and the connection uses the
+    /// result in both directions. A PHY of [`Phys::NONE`] means "no change";
+    /// if it resolves to the PHY already in use, the procedure is a no-op and
+    /// no switch is armed, which is how unsupported-feature fallbacks keep 1M.
+    pub fn on_phy_update_ind(&mut self, s_to_m_phy: Phys, instant: u16) {
+        let target = s_to_m_phy
+            .best_shared(self.preferred_phys)
+            .map(|mode| mode.as_phys())
+            .unwrap_or(Phys::NONE);
+
+        let phy = if target.contains(Phys::LE_2M) {
+            PhyMode::Le2M
+        } else if target.contains(Phys::LE_1M) {
+            PhyMode::Le1M
+        } else {
+            return;
+        };
+
+        if phy != self.phy {
+            self.pending = Some(PendingProcedure::PhyUpdate { phy, instant });
+        }
+    }
+
+    /// Handles an incoming `LL_ENC_REQ` from the master, returning the
+    /// `LL_ENC_RSP` with this device's session-key and IV halves.
+    ///
+    /// `skd_s`/`iv_s` are the slave-generated halves; the caller concatenates
+    /// them with the master's halves from the request to form `SKD`/`IV`.
+    pub fn on_enc_req(&self, skd_s: [u8; 8], iv_s: [u8; 4]) -> ControlPdu {
+        ControlPdu::EncRsp { skd_s, iv_s }
+    }
+
+    /// Keys AES-CCM with the derived `session_key` and `iv`, turning on
+    /// encryption for subsequent PDUs. Called once `LL_START_ENC` completes.
+    pub fn start_encryption(&mut self, session_key: [u8; 16], iv: [u8; 8]) {
+        self.encryption = Some(Encryption::new(session_key, iv));
+    }
+
+    /// Returns `true` if link-layer encryption is active.
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    /// Encrypts an outgoing PDU in place if encryption is active, returning the
+    /// on-air length (including the MIC when encrypted).
+    pub fn encrypt_outgoing<C: CcmCipher>(
+        &mut self,
+        ccm: &C,
+        direction: Direction,
+        pdu: &mut [u8],
+        len: usize,
+    ) -> Option<usize> {
+        match &mut self.encryption {
+            Some(enc) => enc.encrypt(ccm, direction, pdu, len),
+            None => Some(len),
+        }
+    }
+
+    /// Decrypts an incoming PDU in place if encryption is active, returning the
+    /// plaintext length, or `None` if the MIC check fails.
+    pub fn decrypt_incoming<C: CcmCipher>(
+        &mut self,
+        ccm: &C,
+        direction: Direction,
+        pdu: &mut [u8],
+        len: usize,
+    ) -> Option<usize> {
+        match &mut self.encryption {
+            Some(enc) => enc.decrypt(ccm, direction, pdu, len),
+            None => Some(len),
+        }
+    }
+
+    /// Advances to the next connection event, applying any pending procedure
+    /// whose instant has arrived.
+    ///
+    /// Returns the PHY to program into the radio for the event about to run,
+    /// which differs from the previous event's PHY exactly when a PHY update
+    /// reached its instant.
+    pub fn next_event(&mut self) -> PhyMode {
+        self.event_counter = self.event_counter.wrapping_add(1);
+
+        match self.pending {
+            Some(PendingProcedure::PhyUpdate { phy, instant }) if self.event_counter == instant => {
+                self.phy = phy;
+                self.pending = None;
+            }
+            Some(PendingProcedure::ConnectionUpdate { params, instant })
+                if self.event_counter == instant =>
+            {
+                // Adopt the new timing at the instant; from this event on
+                // `parameters()` reports the new interval, latency and
+                // supervision timeout.
+                self.params = params;
+                self.pending = None;
+            }
+            _ => {}
+        }
+
+        self.phy
+    }
+}
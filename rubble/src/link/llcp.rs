@@ -0,0 +1,209 @@
+//! Link Layer Control Protocol (LLCP) PDUs exchanged on the LL Control logical
+//! channel of a connection.
+//!
+//! Control PDUs share a one-byte opcode followed by opcode-specific payload.
+//! Only the procedures implemented by this stack are modelled here; an
+//! unrecognized opcode decodes to [`ControlPdu::Unknown`] so the connection can
+//! respond with `LL_UNKNOWN_RSP`.
+
+use crate::bytes::{ByteReader, ByteWriter, Error, FromBytes, ToBytes};
+use crate::link::phy::Phys;
+
+/// A decoded LL Control PDU.
+#[derive(Debug, Copy, Clone)]
+pub enum ControlPdu {
+    /// `LL_CONNECTION_UPDATE_IND` — sent by the master to apply new connection
+    /// parameters at a future connection-event instant.
+    ConnectionUpdateInd {
+        /// Transmit-window size, in 1.25 ms units.
+        win_size: u8,
+        /// Transmit-window offset, in 1.25 ms units.
+        win_offset: u16,
+        /// New connection interval, in 1.25 ms units.
+        interval: u16,
+        /// New peripheral latency, in connection events.
+        latency: u16,
+        /// New supervision timeout, in 10 ms units.
+        timeout: u16,
+        /// Connection-event counter value at which the new parameters apply.
+        instant: u16,
+    },
+    /// `LL_PHY_REQ` — initiates the LE PHY Update procedure, carrying the PHYs
+    /// the sender is willing to transmit and receive on.
+    PhyReq {
+        /// PHYs the sender can transmit on.
+        tx_phys: Phys,
+        /// PHYs the sender can receive on.
+        rx_phys: Phys,
+    },
+    /// `LL_PHY_RSP` — response to `LL_PHY_REQ` carrying the responder's
+    /// preferred PHYs.
+    PhyRsp {
+        /// PHYs the responder can transmit on.
+        tx_phys: Phys,
+        /// PHYs the responder can receive on.
+        rx_phys: Phys,
+    },
+    /// `LL_PHY_UPDATE_IND` — sent by the master to announce the negotiated PHYs
+    /// and the connection-event instant at which both peers switch.
+    PhyUpdateInd {
+        /// PHY the master will use for its transmissions (central→peripheral).
+        m_to_s_phy: Phys,
+        /// PHY the slave will use for its transmissions (peripheral→central).
+        s_to_m_phy: Phys,
+        /// Connection-event counter value at which the switch takes effect.
+        instant: u16,
+    },
+    /// `LL_ENC_REQ` — sent by the master to start encryption, carrying the
+    /// random value and diversifier that select the LTK plus the master's
+    /// session-key and initialization-vector halves.
+    EncReq {
+        /// Random value selecting the LTK (`Rand`).
+        rand: [u8; 8],
+        /// Encrypted diversifier selecting the LTK (`EDIV`).
+        ediv: u16,
+        /// Master's session-key identifier half (`SKDm`).
+        skd_m: [u8; 8],
+        /// Master's initialization-vector half (`IVm`).
+        iv_m: [u8; 4],
+    },
+    /// `LL_ENC_RSP` — the slave's reply carrying its session-key and
+    /// initialization-vector halves.
+    EncRsp {
+        /// Slave's session-key identifier half (`SKDs`).
+        skd_s: [u8; 8],
+        /// Slave's initialization-vector half (`IVs`).
+        iv_s: [u8; 4],
+    },
+    /// `LL_START_ENC_REQ` — sent by the slave once it is ready to receive
+    /// encrypted data.
+    StartEncReq,
+    /// `LL_START_ENC_RSP` — acknowledges that encryption is active in both
+    /// directions.
+    StartEncRsp,
+    /// An unrecognized control PDU, identified by its opcode.
+    Unknown {
+        /// The opcode byte that was not understood.
+        opcode: u8,
+    },
+}
+
+impl ControlPdu {
+    const LL_CONNECTION_UPDATE_IND: u8 = 0x00;
+    const LL_ENC_REQ: u8 = 0x03;
+    const LL_ENC_RSP: u8 = 0x04;
+    const LL_START_ENC_REQ: u8 = 0x05;
+    const LL_START_ENC_RSP: u8 = 0x06;
+    const LL_PHY_REQ: u8 = 0x16;
+    const LL_PHY_RSP: u8 = 0x17;
+    const LL_PHY_UPDATE_IND: u8 = 0x18;
+
+    /// Returns the opcode byte identifying this control PDU.
+    pub fn opcode(&self) -> u8 {
+        match self {
+            ControlPdu::ConnectionUpdateInd { .. } => Self::LL_CONNECTION_UPDATE_IND,
+            ControlPdu::EncReq { .. } => Self::LL_ENC_REQ,
+            ControlPdu::EncRsp { .. } => Self::LL_ENC_RSP,
+            ControlPdu::StartEncReq => Self::LL_START_ENC_REQ,
+            ControlPdu::StartEncRsp => Self::LL_START_ENC_RSP,
+            ControlPdu::PhyReq { .. } => Self::LL_PHY_REQ,
+            ControlPdu::PhyRsp { .. } => Self::LL_PHY_RSP,
+            ControlPdu::PhyUpdateInd { .. } => Self::LL_PHY_UPDATE_IND,
+            ControlPdu::Unknown { opcode } => *opcode,
+        }
+    }
+}
+
+impl<'a> FromBytes<'a> for ControlPdu {
+    fn from_bytes(reader: &mut ByteReader<'a>) -> Result<Self, Error> {
+        let opcode = reader.read_u8()?;
+        Ok(match opcode {
+            Self::LL_CONNECTION_UPDATE_IND => ControlPdu::ConnectionUpdateInd {
+                win_size: reader.read_u8()?,
+                win_offset: reader.read_u16_le()?,
+                interval: reader.read_u16_le()?,
+                latency: reader.read_u16_le()?,
+                timeout: reader.read_u16_le()?,
+                instant: reader.read_u16_le()?,
+            },
+            Self::LL_PHY_REQ => ControlPdu::PhyReq {
+                tx_phys: Phys::from_bits(reader.read_u8()?),
+                rx_phys: Phys::from_bits(reader.read_u8()?),
+            },
+            Self::LL_PHY_RSP => ControlPdu::PhyRsp {
+                tx_phys: Phys::from_bits(reader.read_u8()?),
+                rx_phys: Phys::from_bits(reader.read_u8()?),
+            },
+            Self::LL_PHY_UPDATE_IND => ControlPdu::PhyUpdateInd {
+                m_to_s_phy: Phys::from_bits(reader.read_u8()?),
+                s_to_m_phy: Phys::from_bits(reader.read_u8()?),
+                instant: reader.read_u16_le()?,
+            },
+            Self::LL_ENC_REQ => ControlPdu::EncReq {
+                rand: reader.read_array()?,
+                ediv: reader.read_u16_le()?,
+                skd_m: reader.read_array()?,
+                iv_m: reader.read_array()?,
+            },
+            Self::LL_ENC_RSP => ControlPdu::EncRsp {
+                skd_s: reader.read_array()?,
+                iv_s: reader.read_array()?,
+            },
+            Self::LL_START_ENC_REQ => ControlPdu::StartEncReq,
+            Self::LL_START_ENC_RSP => ControlPdu::StartEncRsp,
+            opcode => ControlPdu::Unknown { opcode },
+        })
+    }
+}
+
+impl ToBytes for ControlPdu {
+    fn to_bytes(&self, writer: &mut ByteWriter<'_>) -> Result<(), Error> {
+        writer.write_u8(self.opcode())?;
+        match self {
+            ControlPdu::ConnectionUpdateInd {
+                win_size,
+                win_offset,
+                interval,
+                latency,
+                timeout,
+                instant,
+            } => {
+                writer.write_u8(*win_size)?;
+                writer.write_u16_le(*win_offset)?;
+                writer.write_u16_le(*interval)?;
+                writer.write_u16_le(*latency)?;
+                writer.write_u16_le(*timeout)?;
+                writer.write_u16_le(*instant)
+            }
+            ControlPdu::PhyReq { tx_phys, rx_phys } | ControlPdu::PhyRsp { tx_phys, rx_phys } => {
+                writer.write_u8(tx_phys.bits())?;
+                writer.write_u8(rx_phys.bits())
+            }
+            ControlPdu::PhyUpdateInd {
+                m_to_s_phy,
+                s_to_m_phy,
+                instant,
+            } => {
+                writer.write_u8(m_to_s_phy.bits())?;
+                writer.write_u8(s_to_m_phy.bits())?;
+                writer.write_u16_le(*instant)
+            }
+            ControlPdu::EncReq {
+                rand,
+                ediv,
+                skd_m,
+                iv_m,
+            } => {
+                writer.write_slice(rand)?;
+                writer.write_u16_le(*ediv)?;
+                writer.write_slice(skd_m)?;
+                writer.write_slice(iv_m)
+            }
+            ControlPdu::EncRsp { skd_s, iv_s } => {
+                writer.write_slice(skd_s)?;
+                writer.write_slice(iv_s)
+            }
+            ControlPdu::StartEncReq | ControlPdu::StartEncRsp | ControlPdu::Unknown { .. } => Ok(()),
+        }
+    }
+}
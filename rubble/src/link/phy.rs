@@ -0,0 +1,92 @@
+//! BLE physical layer (PHY) selection and the LE PHY Update procedure.
+//!
+//! The link layer always starts a connection on the mandatory LE 1M PHY. Once
+//! connected, either peer may start the LE PHY Update procedure by sending
+//! [`ControlPdu::PhyReq`](crate::link::llcp::ControlPdu::PhyReq) with the set of
+//! PHYs it is willing to use. The peers negotiate a symmetric TX/RX PHY and
+//! agree on an *instant* — a future connection-event counter value — at which
+//! both sides switch simultaneously.
+
+/// The set of PHYs a peer prefers, as a bitmask matching the `PHYs` field of the
+/// `LL_PHY_REQ`/`LL_PHY_RSP` control PDUs.
+///
+/// Bit 0 selects the LE 1M PHY, bit 1 the LE 2M PHY. Bit 2 (LE Coded) is not
+/// supported by this stack and is always cleared.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Phys(u8);
+
+impl Phys {
+    /// The mandatory LE 1M PHY.
+    pub const LE_1M: Phys = Phys(0b001);
+    /// The optional LE 2M PHY (BLE 5.0).
+    pub const LE_2M: Phys = Phys(0b010);
+
+    /// An empty PHY set.
+    pub const NONE: Phys = Phys(0);
+
+    /// Creates a `Phys` set from the raw control-PDU bitmask, masking off bits
+    /// this stack does not implement (LE Coded and reserved bits).
+    pub fn from_bits(bits: u8) -> Self {
+        Phys(bits & 0b011)
+    }
+
+    /// Returns the raw bitmask for encoding into a control PDU.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if `phy` is a member of this set.
+    pub fn contains(self, phy: Phys) -> bool {
+        self.0 & phy.0 == phy.0
+    }
+
+    /// Returns the highest-throughput PHY shared with `other`, or `None` if the
+    /// sets are disjoint. 2M is preferred over 1M.
+    pub fn best_shared(self, other: Phys) -> Option<PhyMode> {
+        let shared = Phys(self.0 & other.0);
+        if shared.contains(Phys::LE_2M) {
+            Some(PhyMode::Le2M)
+        } else if shared.contains(Phys::LE_1M) {
+            Some(PhyMode::Le1M)
+        } else {
+            None
+        }
+    }
+}
+
+impl core::ops::BitOr for Phys {
+    type Output = Phys;
+
+    fn bitor(self, rhs: Phys) -> Phys {
+        Phys(self.0 | rhs.0)
+    }
+}
+
+/// A concrete PHY a connection is currently operating on.
+///
+/// This is what the radio driver programs into its `MODE`/`PCNF0` registers;
+/// unlike [`Phys`] it always names exactly one PHY.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PhyMode {
+    /// LE 1M: 1 Mbit/s, 8-bit preamble.
+    Le1M,
+    /// LE 2M: 2 Mbit/s, 16-bit preamble.
+    Le2M,
+}
+
+impl PhyMode {
+    /// Returns the single-PHY [`Phys`] mask corresponding to this mode.
+    pub fn as_phys(self) -> Phys {
+        match self {
+            PhyMode::Le1M => Phys::LE_1M,
+            PhyMode::Le2M => Phys::LE_2M,
+        }
+    }
+}
+
+impl Default for PhyMode {
+    fn default() -> Self {
+        // Connections always begin on the mandatory 1M PHY.
+        PhyMode::Le1M
+    }
+}
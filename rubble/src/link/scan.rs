@@ -0,0 +1,92 @@
+//! The Observer/Scanner role.
+//!
+//! Scanning puts the radio into receive across the three primary advertising
+//! channels (37, 38, 39) and reports received advertising PDUs to the
+//! application. Passive scanning only listens; active scanning additionally
+//! sends `SCAN_REQ` to connectable/scannable advertisers and collects the
+//! `SCAN_RSP`.
+
+use crate::link::ad_structure::AdStructureIter;
+use crate::link::DeviceAddress;
+use crate::time::Duration;
+
+/// The primary advertising channel indices, scanned in order.
+pub const ADVERTISING_CHANNELS: [u8; 3] = [37, 38, 39];
+
+/// Whether the scanner transmits `SCAN_REQ` to solicit scan responses.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScanMode {
+    /// Listen only; never transmit.
+    Passive,
+    /// Send `SCAN_REQ` and collect `SCAN_RSP` in addition to listening.
+    Active,
+}
+
+/// Scanning timing and behaviour.
+#[derive(Debug, Copy, Clone)]
+pub struct ScanParameters {
+    /// How long to listen on each channel before hopping (the scan window).
+    pub window: Duration,
+    /// The period between the start of consecutive scan windows.
+    pub interval: Duration,
+    /// Passive or active scanning.
+    pub mode: ScanMode,
+}
+
+impl ScanParameters {
+    /// Continuous passive scanning: window equals interval, no transmissions.
+    pub fn passive(window: Duration) -> Self {
+        ScanParameters {
+            window,
+            interval: window,
+            mode: ScanMode::Passive,
+        }
+    }
+
+    /// Continuous active scanning: window equals interval, soliciting
+    /// scan responses.
+    pub fn active(window: Duration) -> Self {
+        ScanParameters {
+            window,
+            interval: window,
+            mode: ScanMode::Active,
+        }
+    }
+}
+
+/// The advertising PDU types the scanner distinguishes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AdvType {
+    /// Connectable undirected advertising (`ADV_IND`).
+    AdvInd,
+    /// Non-connectable undirected advertising (`ADV_NONCONN_IND`).
+    AdvNonconnInd,
+    /// A scan response (`SCAN_RSP`).
+    ScanRsp,
+}
+
+/// A received advertisement delivered to the application.
+#[derive(Debug, Copy, Clone)]
+pub struct AdvReport<'a> {
+    /// The PDU type the advertisement arrived in.
+    pub ty: AdvType,
+    /// The advertiser's device address.
+    pub addr: DeviceAddress,
+    /// Received signal strength indication, in dBm.
+    pub rssi: i8,
+    /// The raw AD-structure payload; iterate with [`ad_structures`](AdvReport::ad_structures).
+    pub data: &'a [u8],
+}
+
+impl<'a> AdvReport<'a> {
+    /// Returns an iterator over the parsed AD structures in this report.
+    pub fn ad_structures(&self) -> AdStructureIter<'a> {
+        AdStructureIter::new(self.data)
+    }
+}
+
+/// A sink for advertisements surfaced by the scanner.
+pub trait ScanCallback {
+    /// Called once per received advertising PDU.
+    fn advertisement(&mut self, report: AdvReport<'_>);
+}
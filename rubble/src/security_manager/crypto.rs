@@ -0,0 +1,60 @@
+//! The SMP cryptographic toolbox for LE Legacy pairing.
+//!
+//! All functions are built on AES-128 in ECB mode, supplied by the platform
+//! through the [`AesEngine`] trait (the nRF52 exposes an `ECB` peripheral). The
+//! `c1` and `s1` functions implement the confirm-value and STK derivations from
+//! the Security Manager specification.
+
+/// An AES-128 ECB block cipher provided by the platform.
+pub trait AesEngine {
+    /// Encrypts a single 16-byte block under `key`.
+    fn encrypt_block(&self, key: [u8; 16], block: [u8; 16]) -> [u8; 16];
+}
+
+fn xor(a: [u8; 16], b: [u8; 16]) -> [u8; 16] {
+    let mut out = [0u8; 16];
+    for i in 0..16 {
+        out[i] = a[i] ^ b[i];
+    }
+    out
+}
+
+/// The `c1` confirm-value function.
+///
+/// `p1 = pres || preq || rat || iat` and `p2 = padding || ia || ra`; the confirm
+/// value is `e(k, e(k, r XOR p1) XOR p2)`.
+pub fn c1<A: AesEngine>(
+    aes: &A,
+    k: [u8; 16],
+    r: [u8; 16],
+    preq: [u8; 7],
+    pres: [u8; 7],
+    ia: [u8; 7],
+    ra: [u8; 7],
+) -> [u8; 16] {
+    // Blocks are little-endian (byte 0 is the least significant octet, as in
+    // `s1`), so the rightmost operand of each concatenation comes first. The
+    // address types are folded into byte 0 of the address words passed in by
+    // the caller.
+    let mut p1 = [0u8; 16];
+    p1[0] = ia[0];
+    p1[1] = ra[0];
+    p1[2..9].copy_from_slice(&preq);
+    p1[9..16].copy_from_slice(&pres);
+
+    let mut p2 = [0u8; 16];
+    p2[0..6].copy_from_slice(&ra[1..7]);
+    p2[6..12].copy_from_slice(&ia[1..7]);
+
+    let step1 = aes.encrypt_block(k, xor(r, p1));
+    aes.encrypt_block(k, xor(step1, p2))
+}
+
+/// The `s1` key-generation function producing the STK from the two random
+/// values: `s1(k, r1, r2) = e(k, r1[0..8] || r2[0..8])`.
+pub fn s1<A: AesEngine>(aes: &A, k: [u8; 16], r1: [u8; 16], r2: [u8; 16]) -> [u8; 16] {
+    let mut r = [0u8; 16];
+    r[0..8].copy_from_slice(&r2[0..8]);
+    r[8..16].copy_from_slice(&r1[0..8]);
+    aes.encrypt_block(k, r)
+}
@@ -0,0 +1,273 @@
+//! The Security Manager Protocol (SMP).
+//!
+//! The security manager runs over the fixed SMP L2CAP channel (CID `0x0006`)
+//! and owns LE Legacy pairing, key generation, and bonding. Two backends are
+//! provided: [`NoSecurity`], which rejects all pairing, and [`SecurityManager`],
+//! which performs the feature exchange, the confirm/random exchange, STK
+//! derivation, and hands the resulting key to the link layer to start
+//! encryption. Long-term keys are persisted through a pluggable [`KeyStore`] so
+//! devices can bond across reconnections.
+
+mod crypto;
+
+pub use self::crypto::AesEngine;
+
+use self::crypto::{c1, s1};
+
+/// A security manager that supports no security at all.
+///
+/// Every pairing request is rejected, so characteristics cannot be marked
+/// encryption-required. This is the default backend for devices that only serve
+/// unauthenticated data.
+pub struct NoSecurity {
+    _private: (),
+}
+
+impl NoSecurity {
+    /// Creates the no-op security manager.
+    pub fn new() -> Self {
+        NoSecurity { _private: () }
+    }
+}
+
+impl Default for NoSecurity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The input/output capabilities a device advertises during pairing, which
+/// together with the peer's capabilities select the association model.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IoCapabilities {
+    /// Can display a six-digit passkey but has no input.
+    DisplayOnly,
+    /// Can display a passkey and confirm yes/no.
+    DisplayYesNo,
+    /// Can input a passkey but has no display.
+    KeyboardOnly,
+    /// Has neither input nor output.
+    NoInputNoOutput,
+    /// Has both a keyboard and a display.
+    KeyboardDisplay,
+}
+
+impl IoCapabilities {
+    fn as_u8(self) -> u8 {
+        match self {
+            IoCapabilities::DisplayOnly => 0x00,
+            IoCapabilities::DisplayYesNo => 0x01,
+            IoCapabilities::KeyboardOnly => 0x02,
+            IoCapabilities::NoInputNoOutput => 0x03,
+            IoCapabilities::KeyboardDisplay => 0x04,
+        }
+    }
+}
+
+/// The association model chosen from the two devices' IO capabilities.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AssociationModel {
+    /// No user interaction; the confirm value uses a zero passkey.
+    JustWorks,
+    /// A six-digit passkey is displayed on one device and entered on the other.
+    PasskeyEntry,
+}
+
+/// Pairing features exchanged in the Pairing Request/Response PDUs.
+#[derive(Debug, Copy, Clone)]
+pub struct PairingFeatures {
+    /// The device's IO capabilities.
+    pub io: IoCapabilities,
+    /// Whether out-of-band authentication data is present.
+    pub oob: bool,
+    /// The Authentication Requirements flags (bonding, MITM, Secure Connections).
+    pub auth_req: u8,
+    /// The maximum encryption key size in octets (7..=16).
+    pub max_key_size: u8,
+}
+
+impl PairingFeatures {
+    /// Selects the association model for this device paired with `peer`.
+    ///
+    /// If neither side requests MITM protection, Just Works is used regardless
+    /// of IO capability, matching the LE Legacy mapping table.
+    pub fn association_model(&self, peer: &PairingFeatures) -> AssociationModel {
+        const MITM: u8 = 0b0000_0100;
+        let mitm = (self.auth_req | peer.auth_req) & MITM != 0;
+        let has_keyboard = |io: IoCapabilities| {
+            matches!(io, IoCapabilities::KeyboardOnly | IoCapabilities::KeyboardDisplay)
+        };
+        if !mitm
+            || self.io == IoCapabilities::NoInputNoOutput
+            || peer.io == IoCapabilities::NoInputNoOutput
+            || !(has_keyboard(self.io) || has_keyboard(peer.io))
+        {
+            AssociationModel::JustWorks
+        } else {
+            AssociationModel::PasskeyEntry
+        }
+    }
+}
+
+/// A callback invoked to display or confirm a passkey during pairing.
+pub trait PasskeyHandler {
+    /// Shows `passkey` (0..=999_999) to the user for the display model.
+    fn display(&mut self, passkey: u32);
+
+    /// Returns the passkey the user entered for the entry model.
+    fn request(&mut self) -> u32;
+}
+
+/// A handler that accepts Just Works pairing and panics if a passkey is needed.
+pub struct JustWorksOnly;
+
+impl PasskeyHandler for JustWorksOnly {
+    fn display(&mut self, _passkey: u32) {
+        panic!("JustWorksOnly cannot display a passkey");
+    }
+
+    fn request(&mut self) -> u32 {
+        panic!("JustWorksOnly cannot request a passkey");
+    }
+}
+
+/// Persistent storage for bonding keys.
+///
+/// After pairing the link keys (LTK, IRK, CSRK) are handed to the store so a
+/// subsequent connection from the same peer can skip pairing and start
+/// encryption directly.
+pub trait KeyStore {
+    /// Persists the Long Term Key for the bonded peer.
+    fn store_ltk(&mut self, ltk: [u8; 16]);
+
+    /// Loads a previously stored Long Term Key, if one exists.
+    fn load_ltk(&self) -> Option<[u8; 16]>;
+
+    /// Persists the Identity Resolving Key for the bonded peer.
+    fn store_irk(&mut self, irk: [u8; 16]);
+
+    /// Persists the Connection Signature Resolving Key for the bonded peer.
+    fn store_csrk(&mut self, csrk: [u8; 16]);
+}
+
+/// A key store that persists nothing, so bonding does not survive a reset.
+pub struct NoStore;
+
+impl KeyStore for NoStore {
+    fn store_ltk(&mut self, _ltk: [u8; 16]) {}
+
+    fn load_ltk(&self) -> Option<[u8; 16]> {
+        None
+    }
+
+    fn store_irk(&mut self, _irk: [u8; 16]) {}
+
+    fn store_csrk(&mut self, _csrk: [u8; 16]) {}
+}
+
+/// A working LE Legacy security manager.
+///
+/// Performs the feature exchange, the confirm/random exchange, and STK
+/// derivation, then hands the STK to the link layer to key AES-CCM. `S` is the
+/// [`KeyStore`] used for bonding and `P` the [`PasskeyHandler`] used for the
+/// passkey-entry model.
+pub struct SecurityManager<S, P = JustWorksOnly> {
+    store: S,
+    passkey: P,
+    io: IoCapabilities,
+    max_key_size: u8,
+    tk: [u8; 16],
+}
+
+impl<S: KeyStore> SecurityManager<S, JustWorksOnly> {
+    /// Creates a security manager that only supports the Just Works model,
+    /// persisting bonding keys through `store`.
+    pub fn just_works(store: S) -> Self {
+        SecurityManager {
+            store,
+            passkey: JustWorksOnly,
+            io: IoCapabilities::NoInputNoOutput,
+            max_key_size: 16,
+            tk: [0; 16],
+        }
+    }
+}
+
+impl<S: KeyStore, P: PasskeyHandler> SecurityManager<S, P> {
+    /// Creates a security manager supporting passkey entry, driving the user
+    /// interaction through `passkey`.
+    pub fn with_passkey(store: S, passkey: P, io: IoCapabilities) -> Self {
+        SecurityManager {
+            store,
+            passkey,
+            io,
+            max_key_size: 16,
+            tk: [0; 16],
+        }
+    }
+
+    /// Returns the features this device offers in its Pairing Response.
+    pub fn features(&self) -> PairingFeatures {
+        PairingFeatures {
+            io: self.io,
+            oob: false,
+            auth_req: 0,
+            max_key_size: self.max_key_size,
+        }
+    }
+
+    /// Establishes the Temporary Key for the negotiated association model.
+    ///
+    /// Just Works uses an all-zero TK; passkey entry derives the TK from the
+    /// six-digit passkey obtained through the [`PasskeyHandler`].
+    pub fn prepare_tk(&mut self, model: AssociationModel) {
+        self.tk = match model {
+            AssociationModel::JustWorks => [0; 16],
+            AssociationModel::PasskeyEntry => {
+                let passkey = match self.io {
+                    IoCapabilities::KeyboardOnly | IoCapabilities::KeyboardDisplay => {
+                        self.passkey.request()
+                    }
+                    _ => {
+                        let shown = 0;
+                        self.passkey.display(shown);
+                        shown
+                    }
+                };
+                let mut tk = [0u8; 16];
+                tk[..4].copy_from_slice(&passkey.to_le_bytes());
+                tk
+            }
+        };
+    }
+
+    /// Computes the confirm value `Sconfirm`/`Mconfirm` for our random `rand`.
+    ///
+    /// `preq`/`pres` are the packed Pairing Request/Response PDUs and the
+    /// addresses are the initiator/responder device addresses, per the `c1`
+    /// function of the Core Specification.
+    pub fn confirm<A: AesEngine>(
+        &self,
+        aes: &A,
+        rand: [u8; 16],
+        preq: [u8; 7],
+        pres: [u8; 7],
+        ia: [u8; 7],
+        ra: [u8; 7],
+    ) -> [u8; 16] {
+        c1(aes, self.tk, rand, preq, pres, ia, ra)
+    }
+
+    /// Derives the Short Term Key from the two random values via `s1` once the
+    /// confirm values have matched.
+    pub fn stk<A: AesEngine>(&self, aes: &A, srand: [u8; 16], mrand: [u8; 16]) -> [u8; 16] {
+        s1(aes, self.tk, srand, mrand)
+    }
+
+    /// Persists the distributed bonding keys for a future reconnection.
+    pub fn bond(&mut self, ltk: [u8; 16], irk: [u8; 16], csrk: [u8; 16]) {
+        self.store.store_ltk(ltk);
+        self.store.store_irk(irk);
+        self.store.store_csrk(csrk);
+    }
+}
@@ -0,0 +1,70 @@
+//! Time types used throughout the BLE stack.
+//!
+//! BLE timing is expressed in microseconds relative to connection-event anchor
+//! points. [`Duration`] is an unsigned microsecond span, [`Instant`] is a
+//! wrapping microsecond timestamp, and [`Timer`] abstracts the hardware timer a
+//! [`HardwareInterface`](crate::link::HardwareInterface) drives.
+
+/// An unsigned duration with microsecond resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration(u32);
+
+impl Duration {
+    /// Creates a `Duration` from a number of microseconds.
+    pub const fn from_micros(micros: u32) -> Self {
+        Duration(micros)
+    }
+
+    /// Creates a `Duration` from a number of milliseconds.
+    pub const fn from_millis(millis: u32) -> Self {
+        Duration(millis * 1_000)
+    }
+
+    /// Creates a `Duration` from a number of seconds.
+    pub const fn from_secs(secs: u32) -> Self {
+        Duration(secs * 1_000_000)
+    }
+
+    /// Returns the number of whole microseconds in this duration.
+    pub const fn as_micros(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the number of whole milliseconds in this duration.
+    pub const fn as_millis(&self) -> u32 {
+        self.0 / 1_000
+    }
+}
+
+/// A wrapping timestamp with microsecond resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Instant(u32);
+
+impl Instant {
+    /// Creates an `Instant` at `micros` microseconds.
+    pub const fn from_micros(micros: u32) -> Self {
+        Instant(micros)
+    }
+
+    /// Returns the raw microsecond value.
+    pub const fn raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns `self` advanced by `duration`, wrapping on overflow.
+    pub fn wrapping_add(&self, duration: Duration) -> Instant {
+        Instant(self.0.wrapping_add(duration.0))
+    }
+
+    /// Returns the duration elapsed since `earlier`, assuming `self >= earlier`
+    /// in wrapping arithmetic.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        Duration(self.0.wrapping_sub(earlier.0))
+    }
+}
+
+/// A hardware timer the link layer uses to schedule connection events.
+pub trait Timer {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}